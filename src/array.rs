@@ -0,0 +1,227 @@
+//! Stack-allocated backing storage for small, fixed-size [Vector](../struct.Vector.html)s.
+//!
+//! See [ArrayVector](struct.ArrayVector.html) for details.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::{Index, IndexMut};
+use std::ptr;
+
+use typenum::consts::*;
+use typenum::{IsLess, True, Unsigned};
+
+/// Maps a typenum length to its backing `[MaybeUninit<T>; N]` buffer.
+///
+/// Stable Rust has no way to index an array by a generic type's associated const, so this is
+/// implemented for a bounded set of lengths (`U0` through `U32`) by the `array_data_impl!`
+/// macro below, rather than for every `L: Unsigned`.
+pub trait ArrayData<T>: Unsigned {
+    type Buffer;
+
+    fn uninit_buffer() -> Self::Buffer;
+    fn as_slice(buffer: &Self::Buffer) -> &[MaybeUninit<T>];
+    fn as_mut_slice(buffer: &mut Self::Buffer) -> &mut [MaybeUninit<T>];
+}
+
+macro_rules! array_data_impl {
+    ($($len:ty => $n:expr),* $(,)?) => {
+        $(
+            impl<T> ArrayData<T> for $len {
+                type Buffer = [MaybeUninit<T>; $n];
+
+                fn uninit_buffer() -> Self::Buffer {
+                    // Safety: an array of `MaybeUninit<T>` is valid without initializing its
+                    // elements.
+                    unsafe { MaybeUninit::uninit().assume_init() }
+                }
+
+                fn as_slice(buffer: &Self::Buffer) -> &[MaybeUninit<T>] {
+                    buffer
+                }
+
+                fn as_mut_slice(buffer: &mut Self::Buffer) -> &mut [MaybeUninit<T>] {
+                    buffer
+                }
+            }
+        )*
+    };
+}
+
+array_data_impl! {
+    U0 => 0, U1 => 1, U2 => 2, U3 => 3, U4 => 4, U5 => 5, U6 => 6, U7 => 7,
+    U8 => 8, U9 => 9, U10 => 10, U11 => 11, U12 => 12, U13 => 13, U14 => 14, U15 => 15,
+    U16 => 16, U17 => 17, U18 => 18, U19 => 19, U20 => 20, U21 => 21, U22 => 22, U23 => 23,
+    U24 => 24, U25 => 25, U26 => 26, U27 => 27, U28 => 28, U29 => 29, U30 => 30, U31 => 31,
+    U32 => 32,
+}
+
+/// A vector with compile-time length checking, like [Vector](../struct.Vector.html), backed by
+/// an inline `[T; L::USIZE]`-style buffer instead of a heap-allocated `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate vector;
+/// use vector::array::ArrayVector;
+/// use vector::{index::*, length::*};
+/// fn main() {
+///     let v = array_vector![1, 3, 4];
+///     assert_eq!(v.len(), 3);
+///     assert_eq!(v[_0], 1);
+///     assert_eq!(v[_1], 3);
+///     assert_eq!(v[_2], 4);
+/// }
+/// ```
+pub struct ArrayVector<T, L: ArrayData<T>> {
+    inner: L::Buffer,
+    length: PhantomData<L>,
+}
+
+impl<T, L: ArrayData<T>> ArrayVector<T, L> {
+    /// Returns the length of this `ArrayVector`.
+    pub fn len(&self) -> usize {
+        L::USIZE
+    }
+
+    /// Returns `true` if this `ArrayVector` has no elements.
+    pub fn is_empty(&self) -> bool {
+        L::USIZE == 0
+    }
+
+    /// Creates an `ArrayVector` of length `L` from a repeated element.
+    pub fn from_elem(elem: T) -> ArrayVector<T, L>
+    where
+        T: Clone,
+    {
+        let mut inner = L::uninit_buffer();
+        for slot in L::as_mut_slice(&mut inner) {
+            *slot = MaybeUninit::new(elem.clone());
+        }
+        ArrayVector {
+            inner,
+            length: PhantomData,
+        }
+    }
+
+    /// Moves the elements of `arr` into a new `ArrayVector<T, L>`.
+    ///
+    /// Panics if `N != L::USIZE`. The [`array_vector!`](macro.array_vector.html) macro only
+    /// ever calls this with a matching `N`, so prefer that over calling this directly.
+    pub fn from_array<const N: usize>(arr: [T; N]) -> ArrayVector<T, L> {
+        assert_eq!(N, L::USIZE, "array length does not match L::USIZE");
+        let mut inner = L::uninit_buffer();
+        for (slot, elem) in L::as_mut_slice(&mut inner).iter_mut().zip(arr) {
+            *slot = MaybeUninit::new(elem);
+        }
+        ArrayVector {
+            inner,
+            length: PhantomData,
+        }
+    }
+}
+
+impl<T, L: ArrayData<T>> Drop for ArrayVector<T, L> {
+    fn drop(&mut self) {
+        for slot in L::as_mut_slice(&mut self.inner) {
+            unsafe {
+                ptr::drop_in_place(slot.as_mut_ptr());
+            }
+        }
+    }
+}
+
+impl<T, L> fmt::Debug for ArrayVector<T, L>
+where
+    T: fmt::Debug,
+    L: ArrayData<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(L::as_slice(&self.inner).iter().map(|slot| unsafe { &*slot.as_ptr() }))
+            .finish()
+    }
+}
+
+impl<I, T, L> Index<I> for ArrayVector<T, L>
+where
+    L: ArrayData<T>,
+    I: Unsigned + IsLess<L, Output = True>,
+{
+    type Output = T;
+
+    fn index(&self, _: I) -> &T {
+        unsafe { &*L::as_slice(&self.inner)[I::to_usize()].as_ptr() }
+    }
+}
+
+impl<I, T, L> IndexMut<I> for ArrayVector<T, L>
+where
+    L: ArrayData<T>,
+    I: Unsigned + IsLess<L, Output = True>,
+{
+    fn index_mut(&mut self, _: I) -> &mut T {
+        unsafe { &mut *L::as_mut_slice(&mut self.inner)[I::to_usize()].as_mut_ptr() }
+    }
+}
+
+/// `ArrayVector` creation macro. See [ArrayVector](struct.ArrayVector.html) for an example.
+#[macro_export]
+macro_rules! array_vector {
+    ($($x:expr),*) => (
+        $crate::array::ArrayVector::<_, $crate::count_expressions!($($x),*)>::from_array(
+            [$($x),*]
+        )
+    );
+    ($($x:expr,)*) => (array_vector![$($x),*]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{index::*, length::*};
+
+    #[test]
+    fn create() {
+        let v = ArrayVector::<_, U3>::from_array([1, 3, 4]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[_0], 1);
+        assert_eq!(v[_1], 3);
+        assert_eq!(v[_2], 4);
+
+        let v = array_vector![1, 3, 4];
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[_0], 1);
+        assert_eq!(v[_1], 3);
+        assert_eq!(v[_2], 4);
+
+        let v = ArrayVector::<_, U3>::from_elem(1);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[_0], 1);
+        assert_eq!(v[_1], 1);
+        assert_eq!(v[_2], 1);
+    }
+
+    #[test]
+    fn drops_elements() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drop_count = Rc::new(RefCell::new(0));
+        struct DropCounter(Rc<RefCell<i32>>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                *self.0.borrow_mut() += 1;
+            }
+        }
+
+        {
+            let _v = array_vector![
+                DropCounter(drop_count.clone()),
+                DropCounter(drop_count.clone()),
+                DropCounter(drop_count.clone())
+            ];
+        }
+        assert_eq!(*drop_count.borrow(), 3);
+    }
+}
@@ -2,15 +2,20 @@
 //!
 //! See [Vector](struct.Vector.html) for basic details.
 
+extern crate num_traits;
 extern crate typenum;
 
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt;
 use std::marker::PhantomData;
-use std::ops::{Index, IndexMut};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
 
-use typenum::{True, IsLess, Unsigned};
+use num_traits::{Float, Zero};
+use typenum::{True, IsLess, Unsigned, B1, Add1, Sub1, Diff, Sum};
 
 pub use typenum::consts as length;
+pub mod array;
 pub mod index;
 
 /// A vector with compile-time length checking.
@@ -20,7 +25,7 @@ pub mod index;
 /// Basic creation and indexing:
 /// ```
 /// use vector::{Vector, index::*, length::*};
-/// let v = Vector::<_, U3>::from(vec![1, 3, 4]);
+/// let v = Vector::<_, U3>::from_vec_unchecked(vec![1, 3, 4]);
 /// assert_eq!(v.len(), 3);
 /// assert_eq!(v[_0], 1);
 /// assert_eq!(v[_1], 3);
@@ -75,10 +80,181 @@ impl<T, L> Vector<T, L> {
     {
         from_elem::<L, T>(elem)
     }
+
+    /// Applies `f` to each element, returning a `Vector` of the same length `L` but possibly a
+    /// different element type.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Vector<U, L> {
+        Vector {
+            inner: self.inner.into_iter().map(f).collect(),
+            length: PhantomData,
+        }
+    }
+
+    /// Combines this `Vector` with `other` element-wise using `f`.
+    pub fn zip_with<U, V, F: FnMut(T, U) -> V>(self, other: Vector<U, L>, mut f: F) -> Vector<V, L> {
+        Vector {
+            inner: self.inner.into_iter().zip(other.inner).map(|(a, b)| f(a, b)).collect(),
+            length: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over references to this `Vector`'s elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    /// Returns an iterator over mutable references to this `Vector`'s elements.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.inner.iter_mut()
+    }
+
+    /// Builds a `Vector<T, L>` from `iter`, checking that it yields exactly `L::USIZE`
+    /// elements.
+    ///
+    /// On a length mismatch, the collected elements are handed back alongside a
+    /// [`LengthMismatch`] describing the expected and actual lengths, in the same spirit as
+    /// the checked `TryFrom`.
+    pub fn try_collect<It: IntoIterator<Item = T>>(
+        iter: It,
+    ) -> Result<Vector<T, L>, (Vec<T>, LengthMismatch)>
+    where
+        L: Unsigned,
+    {
+        try_from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T, L> IntoIterator for Vector<T, L> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<T, L> Vector<T, L> {
+    /// Appends `elem` to the back of this `Vector`, returning a `Vector` whose length type is
+    /// one greater than `L`.
+    pub fn push(self, elem: T) -> Vector<T, Add1<L>>
+    where
+        L: Add<B1>,
+        Add1<L>: Unsigned,
+    {
+        let mut inner = self.inner;
+        inner.push(elem);
+        Vector {
+            inner,
+            length: PhantomData,
+        }
+    }
+
+    /// Removes the last element of this `Vector`, returning it along with a `Vector` whose
+    /// length type is one less than `L`.
+    ///
+    /// The `L: Sub<B1>` bound means this is only callable when `L` is nonzero, so popping an
+    /// empty `Vector` is a compile error rather than a runtime panic.
+    pub fn pop(self) -> (Vector<T, Sub1<L>>, T)
+    where
+        L: Sub<B1>,
+        Sub1<L>: Unsigned,
+    {
+        let mut inner = self.inner;
+        let elem = inner.pop().expect("Vector length invariant violated");
+        (
+            Vector {
+                inner,
+                length: PhantomData,
+            },
+            elem,
+        )
+    }
+
+    /// Inserts `elem` at index `I`, shifting all elements after it to the right, and returns a
+    /// `Vector` whose length type is one greater than `L`.
+    pub fn insert<I>(self, _: I, elem: T) -> Vector<T, Add1<L>>
+    where
+        L: Add<B1>,
+        Add1<L>: Unsigned,
+        I: Unsigned + IsLess<L, Output = True>,
+    {
+        let mut inner = self.inner;
+        inner.insert(I::to_usize(), elem);
+        Vector {
+            inner,
+            length: PhantomData,
+        }
+    }
+
+    /// Removes the element at index `I`, shifting all elements after it to the left, and
+    /// returns it along with a `Vector` whose length type is one less than `L`.
+    pub fn remove<I>(self, _: I) -> (Vector<T, Sub1<L>>, T)
+    where
+        L: Sub<B1>,
+        Sub1<L>: Unsigned,
+        I: Unsigned + IsLess<L, Output = True>,
+    {
+        let mut inner = self.inner;
+        let elem = inner.remove(I::to_usize());
+        (
+            Vector {
+                inner,
+                length: PhantomData,
+            },
+            elem,
+        )
+    }
+
+    /// Appends the elements of `other` to this `Vector`, returning a `Vector` whose length
+    /// type is the typenum sum of both operands' lengths.
+    pub fn concat<L2>(self, other: Vector<T, L2>) -> Vector<T, Sum<L, L2>>
+    where
+        L: Add<L2>,
+        Sum<L, L2>: Unsigned,
+    {
+        let mut inner = self.inner;
+        inner.extend(other.inner);
+        Vector {
+            inner,
+            length: PhantomData,
+        }
+    }
+
+    /// Splits this `Vector` at index `I`, returning a `Vector` of the first `I::USIZE`
+    /// elements and a `Vector` of the remaining `L::USIZE - I::USIZE` elements.
+    pub fn split_at<I>(self) -> (Vector<T, I>, Vector<T, Diff<L, I>>)
+    where
+        I: Unsigned + IsLess<L, Output = True>,
+        L: Sub<I>,
+        Diff<L, I>: Unsigned,
+    {
+        let mut inner = self.inner;
+        let rest = inner.split_off(I::to_usize());
+        (
+            Vector {
+                inner,
+                length: PhantomData,
+            },
+            Vector {
+                inner: rest,
+                length: PhantomData,
+            },
+        )
+    }
 }
 
-impl<T, L> From<Vec<T>> for Vector<T, L> {
-    fn from(orig: Vec<T>) -> Vector<T, L> {
+impl<T, L> Vector<T, L> {
+    /// Wraps `orig` as a `Vector<T, L>` without checking that `orig.len() == L::USIZE`.
+    ///
+    /// If the lengths don't actually match, the compile-time length guarantee this crate exists
+    /// to provide is violated: indexing may panic or read elements that were never meant to be
+    /// part of the `Vector`. Prefer `TryFrom` / [`try_from_vec`] when `orig`'s length isn't
+    /// already statically known to be `L::USIZE`.
+    ///
+    /// This is inherent rather than a `From` impl: a manual `TryFrom<Vec<T>>` over the same
+    /// types would conflict with the std blanket `impl<T, U: Into<T>> TryFrom<U> for T` that a
+    /// `From<Vec<T>>` impl activates.
+    pub fn from_vec_unchecked(orig: Vec<T>) -> Vector<T, L> {
         Vector {
             inner: orig,
             length: PhantomData,
@@ -86,6 +262,65 @@ impl<T, L> From<Vec<T>> for Vector<T, L> {
     }
 }
 
+/// The error returned when converting a `Vec<T>` into a `Vector<T, L>` whose length doesn't
+/// match `L::USIZE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LengthMismatch {
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "length mismatch: expected {}, found {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl Error for LengthMismatch {}
+
+impl<T, L> TryFrom<Vec<T>> for Vector<T, L>
+where
+    L: Unsigned,
+{
+    type Error = (Vec<T>, LengthMismatch);
+
+    /// Wraps `orig` as a `Vector<T, L>`, checking that `orig.len() == L::USIZE`.
+    ///
+    /// On a length mismatch, `orig` is handed back unchanged alongside a [`LengthMismatch`]
+    /// describing the expected and actual lengths.
+    fn try_from(orig: Vec<T>) -> Result<Vector<T, L>, Self::Error> {
+        try_from_vec(orig)
+    }
+}
+
+/// Attempts to wrap `orig` as a `Vector<T, L>`, checking that `orig.len() == L::USIZE`.
+///
+/// On a length mismatch, `orig` is handed back unchanged alongside a [`LengthMismatch`]
+/// describing the expected and actual lengths.
+pub fn try_from_vec<L: Unsigned, T>(
+    orig: Vec<T>,
+) -> Result<Vector<T, L>, (Vec<T>, LengthMismatch)> {
+    if orig.len() == L::USIZE {
+        Ok(Vector {
+            inner: orig,
+            length: PhantomData,
+        })
+    } else {
+        let actual = orig.len();
+        Err((
+            orig,
+            LengthMismatch {
+                expected: L::USIZE,
+                actual,
+            },
+        ))
+    }
+}
+
 /// Creates a `Vector` of length `L` from a repeated element.
 pub fn from_elem<L: Unsigned, T: Clone>(elem: T) -> Vector<T, L> {
     Vector {
@@ -117,6 +352,112 @@ where
     }
 }
 
+impl<T, L> Add for Vector<T, L>
+where
+    T: Add<Output = T>,
+{
+    type Output = Vector<T, L>;
+
+    /// Adds two `Vector`s element-wise. The shared `L` type parameter statically guarantees
+    /// both operands have the same length, so no runtime length check is needed.
+    ///
+    /// `Sub` below relies on the same guarantee; scalar `Mul` and `Div` take a single `Vector`.
+    fn add(self, other: Vector<T, L>) -> Vector<T, L> {
+        let inner = self
+            .inner
+            .into_iter()
+            .zip(other.inner)
+            .map(|(a, b)| a + b)
+            .collect();
+        Vector {
+            inner,
+            length: PhantomData,
+        }
+    }
+}
+
+impl<T, L> Sub for Vector<T, L>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Vector<T, L>;
+
+    /// Subtracts two `Vector`s element-wise.
+    fn sub(self, other: Vector<T, L>) -> Vector<T, L> {
+        let inner = self
+            .inner
+            .into_iter()
+            .zip(other.inner)
+            .map(|(a, b)| a - b)
+            .collect();
+        Vector {
+            inner,
+            length: PhantomData,
+        }
+    }
+}
+
+impl<T, L> Mul<T> for Vector<T, L>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Vector<T, L>;
+
+    /// Multiplies every element of this `Vector` by `scalar`.
+    fn mul(self, scalar: T) -> Vector<T, L> {
+        let inner = self.inner.into_iter().map(|a| a * scalar).collect();
+        Vector {
+            inner,
+            length: PhantomData,
+        }
+    }
+}
+
+impl<T, L> Div<T> for Vector<T, L>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Vector<T, L>;
+
+    /// Divides every element of this `Vector` by `scalar`.
+    fn div(self, scalar: T) -> Vector<T, L> {
+        let inner = self.inner.into_iter().map(|a| a / scalar).collect();
+        Vector {
+            inner,
+            length: PhantomData,
+        }
+    }
+}
+
+impl<T, L> Vector<T, L> {
+    /// Computes the dot product of this `Vector` with `other`.
+    pub fn dot(self, other: Vector<T, L>) -> T
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero,
+    {
+        self.inner
+            .into_iter()
+            .zip(other.inner)
+            .fold(T::zero(), |acc, (a, b)| acc + a * b)
+    }
+
+    /// Computes the squared Euclidean norm (the dot product of this `Vector` with itself).
+    pub fn norm_squared(self) -> T
+    where
+        T: Add<Output = T> + Mul<Output = T> + Copy + Zero,
+    {
+        self.inner.into_iter().fold(T::zero(), |acc, a| acc + a * a)
+    }
+
+    /// Computes the Euclidean norm of this `Vector`.
+    pub fn norm(self) -> T
+    where
+        T: Float,
+    {
+        self.norm_squared().sqrt()
+    }
+}
+
 impl<I, T, L> IndexMut<I> for Vector<T,L>
 where
     Vec<T>: IndexMut<usize>,
@@ -134,7 +475,7 @@ where
 macro_rules! count_expressions {
     ($last:expr) => (typenum::consts::U1);
     ($head:expr, $($tail:expr),*) => (
-        typenum::operator_aliases::Add1<count_expressions![$($tail),*]>
+        typenum::operator_aliases::Add1<$crate::count_expressions![$($tail),*]>
     )
 }
 
@@ -142,7 +483,7 @@ macro_rules! count_expressions {
 #[macro_export]
 macro_rules! vector {
     ($($x:expr),*) => (
-        $crate::Vector::<_, count_expressions![$($x),*]>::from(vec![$($x),*])
+        $crate::Vector::<_, count_expressions![$($x),*]>::from_vec_unchecked(vec![$($x),*])
     );
     ($($x:expr,)*) => (vector![$($x),*]);
 }
@@ -154,7 +495,7 @@ mod tests {
 
     #[test]
     fn create() {
-        let v = Vector::<_, U3>::from(vec![1, 3, 4]);
+        let v = Vector::<_, U3>::from_vec_unchecked(vec![1, 3, 4]);
         assert_eq!(v.len(), 3);
         assert_eq!(v[_0], 1);
         assert_eq!(v[_1], 3);
@@ -173,4 +514,152 @@ mod tests {
         assert_eq!(v[_1], 1);
         assert_eq!(v[_2], 1);
     }
+
+    #[test]
+    fn push_pop_insert_remove() {
+        let v = vector![1, 3, 4];
+        let v = v.push(5);
+        assert_eq!(v.len(), 4);
+        assert_eq!(v[_3], 5);
+
+        let (v, elem) = v.pop();
+        assert_eq!(elem, 5);
+        assert_eq!(v.len(), 3);
+
+        let v = v.insert(_1, 2);
+        assert_eq!(v.len(), 4);
+        assert_eq!(v[_0], 1);
+        assert_eq!(v[_1], 2);
+        assert_eq!(v[_2], 3);
+        assert_eq!(v[_3], 4);
+
+        let (v, elem) = v.remove(_1);
+        assert_eq!(elem, 2);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[_0], 1);
+        assert_eq!(v[_1], 3);
+        assert_eq!(v[_2], 4);
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let v = Vector::<_, U3>::try_from(vec![1, 3, 4]).unwrap();
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[_0], 1);
+        assert_eq!(v[_1], 3);
+        assert_eq!(v[_2], 4);
+
+        let (orig, err) = Vector::<_, U3>::try_from(vec![1, 3]).unwrap_err();
+        assert_eq!(orig, vec![1, 3]);
+        assert_eq!(
+            err,
+            LengthMismatch {
+                expected: 3,
+                actual: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = vector![1, 2, 3];
+        let b = vector![4, 5, 6];
+
+        let sum = a.clone() + b.clone();
+        assert_eq!(sum[_0], 5);
+        assert_eq!(sum[_1], 7);
+        assert_eq!(sum[_2], 9);
+
+        let diff = b.clone() - a.clone();
+        assert_eq!(diff[_0], 3);
+        assert_eq!(diff[_1], 3);
+        assert_eq!(diff[_2], 3);
+
+        let scaled = a.clone() * 2;
+        assert_eq!(scaled[_0], 2);
+        assert_eq!(scaled[_1], 4);
+        assert_eq!(scaled[_2], 6);
+
+        let halved = scaled / 2;
+        assert_eq!(halved[_0], 1);
+        assert_eq!(halved[_1], 2);
+        assert_eq!(halved[_2], 3);
+
+        assert_eq!(a.clone().dot(b), 32);
+        assert_eq!(a.norm_squared(), 14);
+
+        let unit = vector![3.0, 4.0];
+        assert_eq!(unit.norm(), 5.0);
+    }
+
+    #[test]
+    fn concat_and_split_at() {
+        let a = vector![1, 2];
+        let b = vector![3, 4, 5, 6];
+
+        let v = a.concat(b);
+        assert_eq!(v.len(), 6);
+        assert_eq!(v[_0], 1);
+        assert_eq!(v[_5], 6);
+
+        let (first, rest) = v.split_at::<U2>();
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[_0], 1);
+        assert_eq!(first[_1], 2);
+
+        assert_eq!(rest.len(), 4);
+        assert_eq!(rest[_0], 3);
+        assert_eq!(rest[_3], 6);
+    }
+
+    #[test]
+    fn map_and_zip_with() {
+        let v = vector![1, 2, 3];
+        let doubled = v.clone().map(|x| x * 2);
+        assert_eq!(doubled[_0], 2);
+        assert_eq!(doubled[_1], 4);
+        assert_eq!(doubled[_2], 6);
+
+        let strings = v.map(|x| x.to_string());
+        assert_eq!(strings[_0], "1");
+
+        let a = vector![1, 2, 3];
+        let b = vector![4, 5, 6];
+        let summed = a.zip_with(b, |x, y| x + y);
+        assert_eq!(summed[_0], 5);
+        assert_eq!(summed[_1], 7);
+        assert_eq!(summed[_2], 9);
+    }
+
+    #[test]
+    fn iteration() {
+        let mut v = vector![1, 2, 3];
+
+        let collected: Vec<i32> = v.iter().cloned().collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+
+        for elem in v.iter_mut() {
+            *elem += 1;
+        }
+        assert_eq!(v[_0], 2);
+        assert_eq!(v[_1], 3);
+        assert_eq!(v[_2], 4);
+
+        let collected: Vec<i32> = v.into_iter().collect();
+        assert_eq!(collected, vec![2, 3, 4]);
+
+        let v = Vector::<_, U3>::try_collect(vec![1, 2, 3]).unwrap();
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[_0], 1);
+
+        let (elems, err) = Vector::<_, U3>::try_collect(vec![1, 2]).unwrap_err();
+        assert_eq!(elems, vec![1, 2]);
+        assert_eq!(
+            err,
+            LengthMismatch {
+                expected: 3,
+                actual: 2,
+            }
+        );
+    }
 }